@@ -0,0 +1,91 @@
+use super::service_account::ServiceAccountCredentials;
+use super::TokenCache;
+use crate::error::{GoogleError, Result};
+use reqwest::header::{self, HeaderValue};
+use reqwest::RequestBuilder;
+use std::mem;
+
+/// A pluggable strategy for attaching credentials to an outgoing request.
+///
+/// Implementors let callers bring their own credential source (workload identity, a proxied
+/// token broker, ...) without forking the crate.
+pub trait Authenticate: Send + Sync {
+    fn authenticate(
+        &self,
+        req: &mut RequestBuilder,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+}
+
+/// Authenticate with a Google Cloud API key, sent as the `key` query parameter.
+#[derive(Debug, Clone)]
+pub struct ApiKey(pub String);
+
+impl Authenticate for ApiKey {
+    async fn authenticate(&self, req: &mut RequestBuilder) -> Result<()> {
+        replace_with(req, |builder| builder.query(&[("key", &self.0)]));
+        Ok(())
+    }
+}
+
+/// Authenticate with an already-minted bearer token. The token is forwarded as-is and is never
+/// refreshed, since there are no credentials to mint a replacement from.
+#[derive(Debug, Clone)]
+pub struct OAuthToken(pub String);
+
+impl Authenticate for OAuthToken {
+    async fn authenticate(&self, req: &mut RequestBuilder) -> Result<()> {
+        bearer(req, &self.0)
+    }
+}
+
+/// Authenticate with service-account (or authorized-user) credentials, minting a bearer token
+/// and caching it, refreshed automatically shortly before it expires.
+#[derive(Debug, Clone)]
+pub struct ServiceAccount {
+    credentials: ServiceAccountCredentials,
+    scopes: Vec<String>,
+    cache: TokenCache,
+}
+
+impl ServiceAccount {
+    pub fn new(credentials: ServiceAccountCredentials, scopes: Vec<&str>) -> Self {
+        Self {
+            credentials,
+            scopes: scopes.into_iter().map(String::from).collect(),
+            cache: TokenCache::new(),
+        }
+    }
+}
+
+impl Authenticate for ServiceAccount {
+    async fn authenticate(&self, req: &mut RequestBuilder) -> Result<()> {
+        let token = self.cache.get(&self.credentials, &self.scopes).await?;
+        bearer(req, &token)
+    }
+}
+
+/// No authentication; used for public endpoints such as `TranslateService::list_languages`.
+#[derive(Debug, Clone, Default)]
+pub struct Unauthenticated;
+
+impl Authenticate for Unauthenticated {
+    async fn authenticate(&self, _req: &mut RequestBuilder) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn bearer(req: &mut RequestBuilder, token: &str) -> Result<()> {
+    let header_value = HeaderValue::from_str(&format!("Bearer {token}"))
+        .map_err(|e| GoogleError::Other(e.to_string()))?;
+    replace_with(req, |builder| builder.header(header::AUTHORIZATION, header_value));
+    Ok(())
+}
+
+/// `RequestBuilder`'s methods consume `self` and return a new builder, so mutating one in place
+/// means swapping it out for a throwaway placeholder, transforming it, and swapping the result
+/// back in.
+fn replace_with(req: &mut RequestBuilder, f: impl FnOnce(RequestBuilder) -> RequestBuilder) {
+    let placeholder = reqwest::Client::new().get("about:blank");
+    let builder = mem::replace(req, placeholder);
+    *req = f(builder);
+}
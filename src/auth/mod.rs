@@ -0,0 +1,224 @@
+pub mod authenticate;
+pub mod service_account;
+
+use crate::error::{GoogleError, Result};
+use serde::Deserialize;
+use service_account::ServiceAccountCredentials;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+static GOOGLE_OAUTH_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// How close to its real expiry a cached token may get before it is considered stale and
+/// refreshed early, to avoid racing a request against the token actually expiring.
+const EXPIRY_SKEW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Exchange `credentials` for a bearer access token scoped to `scopes`, returning the token
+/// alongside how many seconds it is valid for.
+///
+/// Service-account keys are exchanged via a self-signed JWT bearer assertion against the key's
+/// `token_uri`; authorized-user credentials are exchanged via their refresh token against the
+/// standard Google OAuth token endpoint.
+async fn fetch_access_token(
+    credentials: &ServiceAccountCredentials,
+    scopes: &[String],
+) -> Result<(String, u64)> {
+    match credentials {
+        ServiceAccountCredentials::ServiceAccount(key) => {
+            let assertion = sign_jwt_assertion(key, scopes)?;
+            let params = [
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion.as_str()),
+            ];
+            request_token(&key.token_uri, &params).await
+        }
+        ServiceAccountCredentials::AuthorizedUser(user) => {
+            let params = [
+                ("grant_type", "refresh_token"),
+                ("client_id", user.client_id.as_str()),
+                ("client_secret", user.client_secret.as_str()),
+                ("refresh_token", user.refresh_token.as_str()),
+            ];
+            request_token(GOOGLE_OAUTH_TOKEN_URI, &params).await
+        }
+    }
+}
+
+async fn request_token(token_uri: &str, params: &[(&str, &str)]) -> Result<(String, u64)> {
+    let client = reqwest::Client::new();
+    let response = client.post(token_uri).form(params).send().await?;
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(GoogleError::Other(format!(
+            "token endpoint returned an error: {body}"
+        )));
+    }
+
+    let token: TokenResponse = response.json().await?;
+    Ok((token.access_token, token.expires_in))
+}
+
+fn sign_jwt_assertion(
+    key: &service_account::ServiceAccountKey,
+    scopes: &[String],
+) -> Result<String> {
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[derive(serde::Serialize)]
+    struct Claims<'a> {
+        iss: &'a str,
+        scope: String,
+        aud: &'a str,
+        iat: u64,
+        exp: u64,
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| GoogleError::Other(format!("system clock is before the unix epoch: {e}")))?
+        .as_secs();
+
+    let claims = Claims {
+        iss: &key.client_email,
+        scope: scopes.join(" "),
+        aud: &key.token_uri,
+        iat: now,
+        exp: now + 3600,
+    };
+
+    let encoding_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes())
+        .map_err(|e| GoogleError::Other(format!("parsing service account private key: {e}")))?;
+
+    encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)
+        .map_err(|e| GoogleError::Other(format!("signing service account JWT assertion: {e}")))
+}
+
+#[derive(Debug, Default)]
+struct CachedToken {
+    token: Option<(String, Instant)>,
+}
+
+/// A bearer token cache shared (via `Arc<Mutex<..>>`) across clones of a service, so that all
+/// clones see a token refreshed by any one of them instead of each minting its own.
+#[derive(Debug, Clone)]
+pub(crate) struct TokenCache {
+    cached: Arc<Mutex<CachedToken>>,
+}
+
+impl TokenCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            cached: Arc::new(Mutex::new(CachedToken::default())),
+        }
+    }
+
+    /// Return the cached token if it is still fresh, otherwise mint a new one and cache it.
+    pub(crate) async fn get(
+        &self,
+        credentials: &ServiceAccountCredentials,
+        scopes: &[String],
+    ) -> Result<String> {
+        self.get_with(|| fetch_access_token(credentials, scopes))
+            .await
+    }
+
+    /// Same as [`TokenCache::get`], but minting a token is delegated to `fetch` instead of
+    /// hard-coded to [`fetch_access_token`], so the expiry-skew logic can be tested without
+    /// making a real network call.
+    async fn get_with<F, Fut>(&self, fetch: F) -> Result<String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<(String, u64)>>,
+    {
+        let mut cached = self.cached.lock().await;
+
+        if let Some((token, expiry)) = &cached.token {
+            if Instant::now() + EXPIRY_SKEW < *expiry {
+                return Ok(token.clone());
+            }
+        }
+
+        let (token, expires_in) = fetch().await?;
+        let expiry = Instant::now() + Duration::from_secs(expires_in);
+        cached.token = Some((token.clone(), expiry));
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn fetches_and_caches_a_token_when_none_is_cached() {
+        let cache = TokenCache::new();
+        let fetches = AtomicUsize::new(0);
+
+        let token = cache
+            .get_with(|| {
+                fetches.fetch_add(1, Ordering::SeqCst);
+                async { Ok(("first-token".to_string(), 3600)) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token, "first-token");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn returns_the_cached_token_without_refetching_while_fresh() {
+        let cache = TokenCache::new();
+        cache.cached.lock().await.token = Some((
+            "cached-token".to_string(),
+            Instant::now() + Duration::from_secs(3600),
+        ));
+
+        let token = cache
+            .get_with(|| async {
+                panic!("fetch should not be called while the cached token is still fresh")
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token, "cached-token");
+    }
+
+    #[tokio::test]
+    async fn refetches_a_token_within_the_expiry_skew_of_expiring() {
+        let cache = TokenCache::new();
+        cache.cached.lock().await.token = Some((
+            "stale-token".to_string(),
+            Instant::now() + EXPIRY_SKEW - Duration::from_secs(1),
+        ));
+
+        let token = cache
+            .get_with(|| async { Ok(("refreshed-token".to_string(), 3600)) })
+            .await
+            .unwrap();
+
+        assert_eq!(token, "refreshed-token");
+    }
+
+    #[tokio::test]
+    async fn a_fetch_error_is_surfaced_and_nothing_is_cached() {
+        let cache = TokenCache::new();
+
+        let result = cache
+            .get_with(|| async { Err(GoogleError::Other("token endpoint down".to_string())) })
+            .await;
+
+        assert!(result.is_err());
+        assert!(cache.cached.lock().await.token.is_none());
+    }
+}
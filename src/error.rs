@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Errors returned by this crate's Google API clients.
+#[derive(Debug, Error)]
+pub enum GoogleError {
+    #[error("request failed: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    #[error("failed to deserialize response: {0}")]
+    Deserialization(#[from] serde_json::Error),
+
+    /// Google's standard error envelope, `{ "error": { "code", "status", "message" } }`,
+    /// returned when a response status is non-2xx and its body parses as that envelope.
+    #[error("Google API returned {status} ({code}): {message}")]
+    Api {
+        code: u16,
+        status: String,
+        message: String,
+    },
+
+    #[error("{0}")]
+    Other(String),
+}
+
+pub type Result<T> = std::result::Result<T, GoogleError>;
+
+#[derive(Debug, Deserialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    code: u16,
+    status: String,
+    message: String,
+}
+
+/// Check a response's status, turning a non-2xx response into a `GoogleError::Api` parsed from
+/// Google's standard error envelope instead of letting a later blind `.json()` call panic on it.
+/// A non-2xx body that is not such an envelope becomes a `GoogleError::Other` holding the raw body.
+pub async fn ensure_success(response: reqwest::Response) -> Result<reqwest::Response> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let body = response.bytes().await?;
+    match serde_json::from_slice::<ErrorEnvelope>(&body) {
+        Ok(envelope) => Err(GoogleError::Api {
+            code: envelope.error.code,
+            status: envelope.error.status,
+            message: envelope.error.message,
+        }),
+        Err(_) => Err(GoogleError::Other(
+            String::from_utf8_lossy(&body).into_owned(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, body: &'static str) -> reqwest::Response {
+        let http_response = http::Response::builder()
+            .status(status)
+            .body(body)
+            .unwrap();
+        reqwest::Response::from(http_response)
+    }
+
+    #[tokio::test]
+    async fn success_status_passes_the_response_through_unchanged() {
+        let result = ensure_success(response(200, "")).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn error_envelope_is_parsed_into_api_error() {
+        let body = r#"{"error": {"code": 404, "status": "NOT_FOUND", "message": "no such location"}}"#;
+        let err = ensure_success(response(404, body)).await.unwrap_err();
+
+        match err {
+            GoogleError::Api { code, status, message } => {
+                assert_eq!(code, 404);
+                assert_eq!(status, "NOT_FOUND");
+                assert_eq!(message, "no such location");
+            }
+            other => panic!("expected GoogleError::Api, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn non_envelope_error_body_falls_back_to_raw_text() {
+        let err = ensure_success(response(500, "upstream is on fire"))
+            .await
+            .unwrap_err();
+
+        match err {
+            GoogleError::Other(message) => assert_eq!(message, "upstream is on fire"),
+            other => panic!("expected GoogleError::Other, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+static ACCOUNT_MANAGEMENT_BASE_URL: &str = "https://mybusinessaccountmanagement.googleapis.com/v1";
+static BUSINESS_INFORMATION_BASE_URL: &str = "https://mybusinessbusinessinformation.googleapis.com/v1";
+static BUSINESS_REVIEWS_BASE_URL: &str = "https://mybusiness.googleapis.com/v4";
+
+#[derive(Clone)]
+pub enum EndPoint {
+    AccountsEndpoint,
+    LocationsEndpoint(String),
+    LocationsDetailsEndpoint(String, String),
+    AdminEndpoint(String),
+    Reviews(String, String),
+    Location(String),
+    ReviewReply(String),
+}
+
+impl EndPoint {
+    /// Build the request URL for this endpoint. Building is a plain string assembly over known
+    /// static hosts, so unlike the calls made against the resulting URL, it cannot fail.
+    pub fn build(self) -> String {
+        match self {
+            EndPoint::AccountsEndpoint => format!("{ACCOUNT_MANAGEMENT_BASE_URL}/accounts"),
+            EndPoint::LocationsEndpoint(account_id) => format!(
+                "{BUSINESS_INFORMATION_BASE_URL}/accounts/{account_id}/locations?pageSize=100"
+            ),
+            EndPoint::LocationsDetailsEndpoint(account_id, read_mask) => format!(
+                "{BUSINESS_INFORMATION_BASE_URL}/accounts/{account_id}/locations?readMask={read_mask}"
+            ),
+            EndPoint::AdminEndpoint(location_name) => {
+                format!("{ACCOUNT_MANAGEMENT_BASE_URL}/{location_name}/admins")
+            }
+            EndPoint::Reviews(account_id, location_name) => format!(
+                "{BUSINESS_REVIEWS_BASE_URL}/accounts/{account_id}/{location_name}/reviews"
+            ),
+            EndPoint::Location(location_name) => {
+                format!("{BUSINESS_INFORMATION_BASE_URL}/{location_name}")
+            }
+            EndPoint::ReviewReply(review_name) => {
+                format!("{BUSINESS_REVIEWS_BASE_URL}/{review_name}/reply")
+            }
+        }
+    }
+}
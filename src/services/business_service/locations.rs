@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Locations {
+    pub locations: Vec<Location>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub name: String,
+    pub title: String,
+    #[serde(rename = "storeCode")]
+    pub store_code: String,
+    pub phone_numbers: Option<PhoneNumbers>,
+    pub website_uri: Option<String>,
+    pub regular_hours: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneNumbers {
+    pub primary_phone: Option<String>,
+    pub additional_phones: Option<Vec<String>>,
+}
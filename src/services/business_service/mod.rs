@@ -1,41 +1,57 @@
 pub mod accounts;
 pub mod endpoint;
 pub mod locations;
+mod pagination;
 
+use crate::auth::authenticate::Authenticate;
+use crate::error::{ensure_success, GoogleError, Result};
+use crate::services::ServiceBase;
 use accounts::{Accounts, Admins, PageAdmins};
-use anyhow::{anyhow, Result};
 use endpoint::EndPoint;
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::stream::{FuturesUnordered, Stream, StreamExt, TryStreamExt};
 use locations::{Location, Locations};
 use log::info;
-use reqwest::{
-    header::{self, HeaderValue},
-    Response,
-};
-use serde::{Deserialize, Serialize};
+use reqwest::{header, Response};
+use serde::Serialize;
 use serde_json::Value;
 
-#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Default, Clone)]
+static BUSINESS_SERVICE_SCOPE: &str = "https://www.googleapis.com/auth/business.manage";
+
+/// Join a field mask's parts into the comma-separated form the API expects, e.g. `["a", "b"]`
+/// becomes `"a,b"`.
+fn join_mask<T: Into<String>>(mask: Vec<T>) -> String {
+    mask.into_iter().map(Into::into).collect::<Vec<String>>().join(",")
+}
+
+#[derive(Debug, Clone)]
 pub struct BusinessService {
-    access_token: String,
-    account_id: Option<String>,
+    base: ServiceBase,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewReply {
+    comment: String,
 }
 
 pub trait BusinessRequest {
     fn request(
         &mut self,
         endpoint: EndPoint,
+        auth: &impl Authenticate,
     ) -> impl std::future::Future<Output = Result<Response>> + Send;
 
     fn resource_request(
         &mut self,
         endpoint: EndPoint,
         next_page_token: Option<serde_json::Value>,
+        auth: &impl Authenticate,
     ) -> impl std::future::Future<Output = Result<Response>> + Send;
     fn update_request(
         &mut self,
         endpoint: EndPoint,
         payload: &Location,
+        update_mask: &str,
+        auth: &impl Authenticate,
     ) -> impl std::future::Future<Output = Result<Response>> + Send;
 
     fn accounts(&mut self) -> impl std::future::Future<Output = Result<Accounts>> + Send;
@@ -51,9 +67,16 @@ pub trait BusinessRequest {
         read_mask: Vec<T>,
     ) -> impl std::future::Future<Output = Result<Locations>> + Send;
 
-    fn update_location(
+    fn update_location<T: Into<String> + Send>(
         &mut self,
         location: &Location,
+        update_mask: Vec<T>,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+
+    fn reply_to_review(
+        &mut self,
+        review_name: &str,
+        comment: &str,
     ) -> impl std::future::Future<Output = Result<()>> + Send;
 
     fn admin(
@@ -63,7 +86,7 @@ pub trait BusinessRequest {
 
     fn admins(
         &mut self,
-        location: &Vec<Location>,
+        location: &[Location],
     ) -> impl std::future::Future<Output = Result<Vec<PageAdmins>>> + Send;
 
     fn reviews_by_location(
@@ -80,112 +103,118 @@ pub trait BusinessRequest {
 impl BusinessService {
     pub fn new(access_token: &str) -> Self {
         BusinessService {
-            access_token: access_token.to_string(),
-            ..Default::default()
+            base: ServiceBase::new_with_token(access_token.to_string()),
         }
     }
+
+    /// Create a `BusinessService` whose credentials come from Application Default Credentials:
+    /// the `GOOGLE_APPLICATION_CREDENTIALS` env var if set, otherwise the `gcloud auth
+    /// application-default login` file. The resulting access token is cached and refreshed
+    /// automatically shortly before it expires.
+    pub fn new_default() -> Result<Self> {
+        let base = ServiceBase::new_default(vec![BUSINESS_SERVICE_SCOPE])?;
+        Ok(BusinessService { base })
+    }
+
+    /// Stream an account's locations one by one, fetching each page lazily instead of collecting
+    /// every page up front.
+    ///
+    /// * `account_id` - account that manages the locations, for service accounts use `"-"`
+    /// * `read_mask` - optional field mask; when empty, the account's locations are returned
+    ///   with Google's default fields
+    pub fn locations_stream<T: Into<String>>(
+        &self,
+        account_id: &str,
+        read_mask: Vec<T>,
+    ) -> impl Stream<Item = Result<Location>> {
+        let read_mask_joined = join_mask(read_mask);
+
+        let endpoint = if read_mask_joined.is_empty() {
+            EndPoint::LocationsEndpoint(account_id.to_string())
+        } else {
+            EndPoint::LocationsDetailsEndpoint(account_id.to_string(), read_mask_joined)
+        };
+
+        pagination::paginate(endpoint, "locations", self.base.clone())
+    }
 }
 
 impl BusinessRequest for BusinessService {
-    async fn request(&mut self, endpoint: EndPoint) -> Result<Response> {
-        let url = EndPoint::build(endpoint).expect("could not build accounts url");
-
-        let client = reqwest::Client::builder().build()?;
-        let res = client
-            .get(url)
-            .header(
-                header::AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", self.access_token.as_str())).unwrap(),
-            )
-            .header(header::CONTENT_TYPE, "application/json")
-            .send()
-            .await
-            .expect("Error with request");
+    async fn request(&mut self, endpoint: EndPoint, auth: &impl Authenticate) -> Result<Response> {
+        let url = EndPoint::build(endpoint);
+
+        let client = reqwest::Client::new();
+        let mut builder = client.get(url).header(header::CONTENT_TYPE, "application/json");
+        auth.authenticate(&mut builder).await?;
+        let res = builder.send().await?;
 
-        Ok(res)
+        ensure_success(res).await
     }
     async fn resource_request(
         &mut self,
         endpoint: EndPoint,
         next_page_token: Option<serde_json::Value>,
+        auth: &impl Authenticate,
     ) -> Result<Response> {
-        let mut url = EndPoint::build(endpoint).expect("could not build accounts url");
+        let mut url = EndPoint::build(endpoint);
         if let Some(token) = next_page_token {
-            url.push_str(format!("&pageToken={}", token.as_str().unwrap()).as_str())
+            if let Some(token) = token.as_str() {
+                url.push_str(format!("&pageToken={token}").as_str())
+            }
         }
 
-        let client = reqwest::Client::builder().build()?;
-        let res = client
-            .get(url)
-            .header(
-                header::AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", self.access_token.as_str())).unwrap(),
-            )
-            .header(header::CONTENT_TYPE, "application/json")
-            .send()
-            .await
-            .expect("Error with request");
+        let client = reqwest::Client::new();
+        let mut builder = client.get(url).header(header::CONTENT_TYPE, "application/json");
+        auth.authenticate(&mut builder).await?;
+        let res = builder.send().await?;
 
-        Ok(res)
+        ensure_success(res).await
     }
 
-    async fn update_request(&mut self, endpoint: EndPoint, payload: &Location) -> Result<Response> {
-        let mut url = EndPoint::build(endpoint).expect("could not build accounts url");
-        url.push_str("?updateMask=title");
-        let client = reqwest::Client::builder().build()?;
-        let res = client
+    async fn update_request(
+        &mut self,
+        endpoint: EndPoint,
+        payload: &Location,
+        update_mask: &str,
+        auth: &impl Authenticate,
+    ) -> Result<Response> {
+        let mut url = EndPoint::build(endpoint);
+        url.push_str(&format!("?updateMask={update_mask}"));
+        let client = reqwest::Client::new();
+        let mut builder = client
             .patch(url)
-            .header(
-                header::AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", self.access_token.as_str())).unwrap(),
-            )
             .header(header::CONTENT_TYPE, "application/json")
-            .json(payload)
-            .send()
-            .await
-            .expect("Error with patch request");
+            .json(payload);
+        auth.authenticate(&mut builder).await?;
+        let res = builder.send().await?;
 
-        Ok(res)
+        ensure_success(res).await
     }
     async fn accounts(&mut self) -> Result<Accounts> {
-        let response = self.request(EndPoint::AccountsEndpoint).await?;
+        let auth = self.base.clone();
+        let response = self.request(EndPoint::AccountsEndpoint, &auth).await?;
         let accounts: Accounts = response.json().await?;
-        if accounts.accounts.len() == 0 {
-            return Err(anyhow!("no accounts, something went wrong!"));
+        if accounts.accounts.is_empty() {
+            return Err(GoogleError::Other(
+                "no accounts, something went wrong!".to_string(),
+            ));
         }
         Ok(accounts)
     }
-    /// must be sequential as the `nextPageToken` is needed to process the rest of the locations
+    /// Collects the full set of locations by draining [`BusinessService::locations_stream`].
     ///
     /// * `account id` - ID of account that manages the locations, for service account use `"-"`
     async fn get_locations(&mut self, account_id: &str) -> Result<Locations> {
-        let mut locations = Locations::default();
-        let mut next_page_token = None;
-        loop {
-            let response = self
-                .resource_request(
-                    EndPoint::LocationsEndpoint(account_id.into()),
-                    next_page_token.clone(),
-                )
-                .await?;
-            let resp: Value = response.json().await?;
-            let val_pages = &resp.get("locations").unwrap().as_array().unwrap().clone();
-            let pages: Vec<Location> = val_pages
-                .iter()
-                .map(|v| serde_json::from_value(v.clone()).unwrap())
-                .collect();
-            locations.locations.extend(pages);
-            next_page_token = resp.get("nextPageToken").cloned();
-            if next_page_token.is_none() {
-                break;
-            };
-        }
-        info!("Retrieved {} locations", locations.locations.len());
-        Ok(locations)
+        let locations: Vec<Location> = self
+            .locations_stream(account_id, Vec::<String>::new())
+            .try_collect()
+            .await?;
+        info!("Retrieved {} locations", locations.len());
+        Ok(Locations { locations })
     }
-    /// must be sequential as the `nextPageToken` is needed to process the rest of the locations
+    /// Collects the full set of locations by draining [`BusinessService::locations_stream`].
     ///
-    ///```rust
+    ///```ignore
     ///let mask = vec![
     ///     "storeCode",
     ///     "title",
@@ -194,7 +223,6 @@ impl BusinessRequest for BusinessService {
     ///];
     ///let access_token = get_token().await;
     ///let mut business_service = BusinessService::new(&access_token);
-
     ///let locations = business_service.get_locations_details("-", mask).await?;
     ///
     ///```
@@ -206,37 +234,19 @@ impl BusinessRequest for BusinessService {
         account_id: &str,
         read_mask: Vec<T>,
     ) -> Result<Locations> {
-        let mut locations = Locations::default();
-        let mut next_page_token = None;
-        let read_mask_str: Vec<String> = read_mask.into_iter().map(Into::into).collect();
-        let read_mask_joined = read_mask_str.join(",");
-        loop {
-            let response = self
-                .resource_request(
-                    EndPoint::LocationsDetailsEndpoint(account_id.into(), read_mask_joined.clone()),
-                    next_page_token.clone(),
-                )
-                .await?;
-            let resp: Value = response.json().await?;
-            let val_pages = &resp.get("locations").unwrap().as_array().unwrap().clone();
-            let pages: Vec<Location> = val_pages
-                .iter()
-                .map(|v| serde_json::from_value(v.clone()).unwrap())
-                .collect();
-            locations.locations.extend(pages);
-            next_page_token = resp.get("nextPageToken").cloned();
-            if next_page_token.is_none() {
-                break;
-            };
-        }
-        info!("Retrieved {} locations", locations.locations.len());
-        Ok(locations)
+        let locations: Vec<Location> = self
+            .locations_stream(account_id, read_mask)
+            .try_collect()
+            .await?;
+        info!("Retrieved {} locations", locations.len());
+        Ok(Locations { locations })
     }
 
     async fn admin(&mut self, location: &Location) -> Result<PageAdmins> {
         let endpoint = EndPoint::AdminEndpoint(location.name.clone());
 
-        let response = self.request(endpoint).await?;
+        let auth = self.base.clone();
+        let response = self.request(endpoint, &auth).await?;
         let resp: Admins = response.json().await?;
 
         Ok(PageAdmins {
@@ -248,7 +258,7 @@ impl BusinessRequest for BusinessService {
         })
     }
 
-    async fn admins(&mut self, locations: &Vec<Location>) -> Result<Vec<PageAdmins>> {
+    async fn admins(&mut self, locations: &[Location]) -> Result<Vec<PageAdmins>> {
         let mut futures = FuturesUnordered::new();
         let mut results: Vec<PageAdmins> = Vec::new();
 
@@ -269,47 +279,92 @@ impl BusinessRequest for BusinessService {
 
     async fn reviews_by_location(&mut self, location: &Location) -> Result<Value> {
         let endpoint = EndPoint::Reviews("-".to_string(), location.name.clone());
-        let res = self.request(endpoint).await.expect("should have reviews");
+        let auth = self.base.clone();
+        let res = self.request(endpoint, &auth).await?;
 
-        let resp: serde_json::Value = res.json().await.expect("should have json");
-        println!("{:#?}", resp);
+        let resp: serde_json::Value = res.json().await?;
         Ok(resp)
     }
 
     async fn review_summary(&mut self, location: &Location) -> Result<Value> {
         let endpoint = EndPoint::Reviews("-".to_string(), location.name.clone());
-        let res = self
-            .request(endpoint)
-            .await
-            .expect("should have reviews for site");
+        let auth = self.base.clone();
+        let res = self.request(endpoint, &auth).await?;
 
-        if !res.status().is_success() {
-            println!("{:#?}", res.status());
-        }
-
-        let resp: serde_json::Value = res.json().await.expect("should have json");
+        let resp: serde_json::Value = res.json().await?;
         let total_reviews = resp.get("totalReviewCount").unwrap_or(&Value::Null);
         let rating = resp.get("averageRating").unwrap_or(&Value::Null);
-        println!("{:#?}", location);
-        //println!("{:#?}", resp);
-        println!(
-            "{:#?} - total reviews {} - average rating {}",
+        info!(
+            "{} - total reviews {} - average rating {}",
             location.title, total_reviews, rating
         );
         Ok(resp)
     }
 
-    async fn update_location(&mut self, location: &Location) -> Result<()> {
+    async fn update_location<T: Into<String> + Send>(
+        &mut self,
+        location: &Location,
+        update_mask: Vec<T>,
+    ) -> Result<()> {
         let endpoint = EndPoint::Location(location.name.clone());
+        let update_mask_joined = join_mask(update_mask);
 
+        let auth = self.base.clone();
         let res = self
-            .update_request(endpoint, location)
-            .await
-            .expect("Should update");
+            .update_request(endpoint, location, &update_mask_joined, &auth)
+            .await?;
 
         let resp: Location = res.json().await?;
-        println!("{:#?}", resp);
+        info!("Updated location {}", resp.name);
 
         Ok(())
     }
+
+    async fn reply_to_review(&mut self, review_name: &str, comment: &str) -> Result<()> {
+        let url = EndPoint::build(EndPoint::ReviewReply(review_name.to_string()));
+        let payload = ReviewReply {
+            comment: comment.to_string(),
+        };
+
+        let client = reqwest::Client::new();
+        let mut builder = client
+            .put(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&payload);
+        let auth = self.base.clone();
+        auth.authenticate(&mut builder).await?;
+        let res = builder.send().await?;
+
+        ensure_success(res).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_mask_comma_separates_multiple_fields() {
+        assert_eq!(join_mask(vec!["storeCode", "title", "phoneNumbers"]), "storeCode,title,phoneNumbers");
+    }
+
+    #[test]
+    fn join_mask_of_a_single_field_has_no_comma() {
+        assert_eq!(join_mask(vec!["title"]), "title");
+    }
+
+    #[test]
+    fn join_mask_of_no_fields_is_empty() {
+        assert_eq!(join_mask(Vec::<&str>::new()), "");
+    }
+
+    #[test]
+    fn reply_to_review_url_is_the_review_name_under_the_reviews_base() {
+        let url = EndPoint::build(EndPoint::ReviewReply("accounts/1/locations/2/reviews/3".to_string()));
+        assert_eq!(
+            url,
+            "https://mybusiness.googleapis.com/v4/accounts/1/locations/2/reviews/3/reply"
+        );
+    }
 }
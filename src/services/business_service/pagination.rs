@@ -0,0 +1,164 @@
+use super::endpoint::EndPoint;
+use crate::auth::authenticate::Authenticate;
+use crate::error::{ensure_success, GoogleError, Result};
+use futures::future::Future;
+use futures::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::VecDeque;
+
+struct PageState<F> {
+    fetch: F,
+    field: &'static str,
+    next_page_token: Option<Value>,
+    buffer: VecDeque<Value>,
+    started: bool,
+}
+
+/// Build a lazy stream of `T` by repeatedly requesting `endpoint`, reading the JSON array at
+/// `field` out of each page, and following `nextPageToken` until the server stops returning one.
+///
+/// Unlike eagerly looping over every page up front, items are yielded as soon as their page has
+/// been fetched, so a caller can start processing (or abandon early) without waiting for the
+/// whole collection to download.
+pub(crate) fn paginate<T, A>(endpoint: EndPoint, field: &'static str, auth: A) -> impl Stream<Item = Result<T>>
+where
+    T: DeserializeOwned,
+    A: Authenticate + Clone,
+{
+    paginate_with(field, move |next_page_token| {
+        let endpoint = endpoint.clone();
+        let auth = auth.clone();
+        async move { fetch_page(endpoint, next_page_token, &auth).await }
+    })
+}
+
+/// The pagination state machine itself, parameterised over how a page is actually fetched so it
+/// can be exercised without a live API.
+fn paginate_with<T, F, Fut>(field: &'static str, fetch: F) -> impl Stream<Item = Result<T>>
+where
+    T: DeserializeOwned,
+    F: Fn(Option<Value>) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let state = PageState {
+        fetch,
+        field,
+        next_page_token: None,
+        buffer: VecDeque::new(),
+        started: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                let parsed = serde_json::from_value(item).map_err(GoogleError::from);
+                return Some((parsed, state));
+            }
+
+            if state.started && state.next_page_token.is_none() {
+                return None;
+            }
+            state.started = true;
+
+            let page = match (state.fetch)(state.next_page_token.take()).await {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), state)),
+            };
+
+            state.next_page_token = page.get("nextPageToken").cloned();
+            let items = page
+                .get(state.field)
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            state.buffer.extend(items);
+
+            if state.buffer.is_empty() && state.next_page_token.is_none() {
+                return None;
+            }
+        }
+    })
+}
+
+async fn fetch_page(
+    endpoint: EndPoint,
+    next_page_token: Option<Value>,
+    auth: &impl Authenticate,
+) -> Result<Value> {
+    let mut url = endpoint.build();
+    if let Some(token) = next_page_token.as_ref().and_then(Value::as_str) {
+        url.push_str(&format!("&pageToken={token}"));
+    }
+
+    let client = reqwest::Client::new();
+    let mut builder = client
+        .get(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    auth.authenticate(&mut builder).await?;
+
+    let response = builder.send().await?;
+    let response = ensure_success(response).await?;
+    Ok(response.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+    struct Item {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn drains_a_single_page_with_no_next_page_token() {
+        let stream = paginate_with::<Item, _, _>("items", |_| async {
+            Ok(json!({ "items": [{ "id": 1 }, { "id": 2 }] }))
+        });
+
+        let items: Vec<Item> = stream.map(|r| r.unwrap()).collect().await;
+        assert_eq!(items, vec![Item { id: 1 }, Item { id: 2 }]);
+    }
+
+    #[tokio::test]
+    async fn follows_next_page_token_until_it_disappears() {
+        let calls = AtomicUsize::new(0);
+        let stream = paginate_with::<Item, _, _>("items", |token| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                assert_eq!(token.is_some(), call > 0, "token should only be absent on the first call");
+                match call {
+                    0 => Ok(json!({ "items": [{ "id": 1 }], "nextPageToken": "page-2" })),
+                    1 => Ok(json!({ "items": [{ "id": 2 }] })),
+                    _ => panic!("should not fetch past the page without a nextPageToken"),
+                }
+            }
+        });
+
+        let items: Vec<Item> = stream.map(|r| r.unwrap()).collect().await;
+        assert_eq!(items, vec![Item { id: 1 }, Item { id: 2 }]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn missing_field_yields_no_items_instead_of_erroring() {
+        let stream = paginate_with::<Item, _, _>("items", |_| async { Ok(json!({})) });
+        let items: Vec<Result<Item>> = stream.collect().await;
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_fetch_error_is_surfaced_and_ends_the_stream() {
+        let stream = paginate_with::<Item, _, _>("items", |_| async {
+            Err(GoogleError::Other("boom".to_string()))
+        });
+
+        let items: Vec<Result<Item>> = stream.collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+}
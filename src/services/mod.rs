@@ -0,0 +1,65 @@
+pub mod business_service;
+pub mod translate_service;
+pub mod vertex_ai_service;
+
+use crate::auth::authenticate::{ApiKey, Authenticate, OAuthToken, ServiceAccount};
+use crate::auth::service_account::ServiceAccountCredentials;
+use crate::error::Result;
+use reqwest::RequestBuilder;
+
+/// Shared authentication state for services that talk to Google Cloud APIs via either an API
+/// key, a pre-fetched bearer token, or service-account/OAuth credentials.
+///
+/// `ServiceBase` is itself an `Authenticate` implementor that dispatches to whichever strategy it
+/// was built with. Cloning it shares the underlying token cache, so a token refreshed by one
+/// clone is immediately visible to every other clone instead of each one refreshing
+/// independently.
+#[derive(Debug, Clone)]
+pub struct ServiceBase {
+    auth: ServiceBaseAuth,
+}
+
+#[derive(Debug, Clone)]
+enum ServiceBaseAuth {
+    ApiKey(ApiKey),
+    Token(OAuthToken),
+    ServiceAccount(ServiceAccount),
+}
+
+impl ServiceBase {
+    pub fn new_with_api_key(api_key: String) -> Self {
+        Self {
+            auth: ServiceBaseAuth::ApiKey(ApiKey(api_key)),
+        }
+    }
+
+    /// Wrap an already-minted bearer token. The token is forwarded as-is and is never refreshed,
+    /// since there are no credentials to mint a replacement from.
+    pub(crate) fn new_with_token(access_token: String) -> Self {
+        Self {
+            auth: ServiceBaseAuth::Token(OAuthToken(access_token)),
+        }
+    }
+
+    pub fn new_with_credentials(credentials: ServiceAccountCredentials, scopes: Vec<&str>) -> Self {
+        Self {
+            auth: ServiceBaseAuth::ServiceAccount(ServiceAccount::new(credentials, scopes)),
+        }
+    }
+
+    /// Resolve Application Default Credentials and build a `ServiceBase` scoped to `scopes`.
+    pub fn new_default(scopes: Vec<&str>) -> Result<Self> {
+        let credentials = ServiceAccountCredentials::from_application_default()?;
+        Ok(Self::new_with_credentials(credentials, scopes))
+    }
+}
+
+impl Authenticate for ServiceBase {
+    async fn authenticate(&self, req: &mut RequestBuilder) -> Result<()> {
+        match &self.auth {
+            ServiceBaseAuth::ApiKey(auth) => auth.authenticate(req).await,
+            ServiceBaseAuth::Token(auth) => auth.authenticate(req).await,
+            ServiceBaseAuth::ServiceAccount(auth) => auth.authenticate(req).await,
+        }
+    }
+}
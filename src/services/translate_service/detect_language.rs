@@ -0,0 +1,42 @@
+use super::{BasicServiceType, TranslateService};
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+struct DetectLanguageRequest<'a> {
+    q: Vec<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DetectedLanguage {
+    pub language: String,
+    pub confidence: Option<f32>,
+    #[serde(rename = "isReliable")]
+    pub is_reliable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetectionsData {
+    detections: Vec<Vec<DetectedLanguage>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetectLanguageResponse {
+    data: DetectionsData,
+}
+
+impl TranslateService {
+    /// Detect the language `text` is written in.
+    pub async fn detect_language(&self, text: &str) -> Result<Vec<DetectedLanguage>> {
+        let request = DetectLanguageRequest { q: vec![text] };
+        let response: DetectLanguageResponse = self
+            .call(BasicServiceType::Detect, &self.base, Some(&request))
+            .await?;
+        Ok(response
+            .data
+            .detections
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+}
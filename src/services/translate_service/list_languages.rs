@@ -0,0 +1,31 @@
+use super::{BasicServiceType, TranslateService};
+use crate::auth::authenticate::Unauthenticated;
+use crate::error::Result;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Language {
+    pub language: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguagesData {
+    languages: Vec<Language>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListLanguagesResponse {
+    data: LanguagesData,
+}
+
+impl TranslateService {
+    /// List the languages supported for translation. This is a public endpoint, so it is called
+    /// without forwarding this service's own credentials.
+    pub async fn list_languages(&self) -> Result<Vec<Language>> {
+        let response: ListLanguagesResponse = self
+            .call(BasicServiceType::Languages, &Unauthenticated, None::<&()>)
+            .await?;
+        Ok(response.data.languages)
+    }
+}
@@ -3,7 +3,10 @@ pub mod list_languages;
 pub mod translate_text;
 
 use super::ServiceBase;
+use crate::auth::authenticate::Authenticate;
 use crate::auth::service_account::ServiceAccountCredentials;
+use crate::error::{ensure_success, Result};
+use serde::{de::DeserializeOwned, Serialize};
 
 static TRANSLATE_SERVICE_SCOPE: &str = "https://www.googleapis.com/auth/cloud-translation";
 static TRANSLATE_SERVICE_BASE_URL: &str = "https://translation.googleapis.com/language/translate";
@@ -14,25 +17,61 @@ pub struct TranslateService {
 }
 
 impl TranslateService {
+    /// Create a `TranslateService` backed by Application Default Credentials: the
+    /// `GOOGLE_APPLICATION_CREDENTIALS` file if set, otherwise `gcloud auth application-default
+    /// login`'s cached credentials.
+    pub fn new() -> Result<Self> {
+        let base = ServiceBase::new_default(vec![TRANSLATE_SERVICE_SCOPE])?;
+        Ok(Self { base })
+    }
+
     /// Create `TranslateService` Authenticate by using API keys.
     ///
     /// * `api_key` -  API key to use to authenticate to Google Cloud APIs and services that support API keys.
     pub fn new_with_api_key(api_key: String) -> Self {
-        return Self {
+        Self {
             base: ServiceBase::new_with_api_key(api_key),
-        };
+        }
     }
 
     /// Create `TranslateService` Authenticate by using API keys.
     ///
     /// * `service_account_credentials` -  `ServiceAccountCredentials` to use to authenticate to Google Cloud APIs.
     pub fn new_with_credentials(service_account_credentials: ServiceAccountCredentials) -> Self {
-        return Self {
+        Self {
             base: ServiceBase::new_with_credentials(
                 service_account_credentials,
                 vec![TRANSLATE_SERVICE_SCOPE],
             ),
+        }
+    }
+
+    /// Call one of the Translation v2 endpoints, authenticating with `auth` rather than this
+    /// service's own configured credentials, which `list_languages` relies on to stay
+    /// unauthenticated regardless of how the service was constructed.
+    async fn call<B: Serialize + ?Sized, R: DeserializeOwned>(
+        &self,
+        kind: BasicServiceType,
+        auth: &impl Authenticate,
+        body: Option<&B>,
+    ) -> Result<R> {
+        let mut url = format!("{TRANSLATE_SERVICE_BASE_URL}/v2");
+        let path = kind.path();
+        if !path.is_empty() {
+            url.push('/');
+            url.push_str(path);
+        }
+
+        let client = reqwest::Client::new();
+        let mut builder = match body {
+            Some(body) => client.post(url).json(body),
+            None => client.get(url),
         };
+        auth.authenticate(&mut builder).await?;
+
+        let response = builder.send().await?;
+        let response = ensure_success(response).await?;
+        Ok(response.json().await?)
     }
 }
 
@@ -0,0 +1,61 @@
+use super::{BasicServiceType, TranslateService};
+use crate::error::{GoogleError, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+struct TranslateTextRequest<'a> {
+    q: Vec<&'a str>,
+    target: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a str>,
+    format: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Translation {
+    #[serde(rename = "translatedText")]
+    pub translated_text: String,
+    #[serde(rename = "detectedSourceLanguage")]
+    pub detected_source_language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslationsData {
+    translations: Vec<Translation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslateTextResponse {
+    data: TranslationsData,
+}
+
+impl TranslateService {
+    /// Translate `text` into `target`, optionally pinning the `source` language instead of
+    /// letting Google auto-detect it.
+    ///
+    /// * `text` - the text to translate
+    /// * `target` - the target language code, e.g. `"fr"`
+    /// * `source` - the source language code, e.g. `"en"`; omit to auto-detect
+    pub async fn translate_text(
+        &self,
+        text: &str,
+        target: &str,
+        source: Option<&str>,
+    ) -> Result<Translation> {
+        let request = TranslateTextRequest {
+            q: vec![text],
+            target,
+            source,
+            format: "text",
+        };
+        let response: TranslateTextResponse = self
+            .call(BasicServiceType::Translate, &self.base, Some(&request))
+            .await?;
+        response
+            .data
+            .translations
+            .into_iter()
+            .next()
+            .ok_or_else(|| GoogleError::Other("translate response contained no translations".to_string()))
+    }
+}
@@ -0,0 +1,293 @@
+use crate::auth::authenticate::Authenticate;
+use crate::error::{ensure_success, GoogleError, Result};
+use crate::services::ServiceBase;
+use futures::stream::Stream;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+static VERTEX_AI_SERVICE_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+#[derive(Debug, Clone)]
+pub struct VertexAiService {
+    base: ServiceBase,
+    project_id: String,
+    location: String,
+    model: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Part {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Content {
+    pub role: String,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug, Serialize)]
+struct GenerateContentRequest {
+    contents: Vec<Content>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponsePart {
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseContent {
+    pub role: Option<String>,
+    pub parts: Vec<ResponsePart>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Candidate {
+    pub content: ResponseContent,
+    #[serde(rename = "finishReason")]
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageMetadata {
+    pub prompt_token_count: Option<u32>,
+    pub candidates_token_count: Option<u32>,
+    pub total_token_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateContentResponse {
+    pub candidates: Vec<Candidate>,
+    #[serde(rename = "usageMetadata")]
+    pub usage_metadata: Option<UsageMetadata>,
+}
+
+impl VertexAiService {
+    /// Create a `VertexAiService` for the given model, authenticating with Application Default
+    /// Credentials (`GOOGLE_APPLICATION_CREDENTIALS`, falling back to the local `gcloud` ADC
+    /// file) scoped to `cloud-platform`.
+    ///
+    /// * `project_id` - the GCP project hosting the model
+    /// * `location` - the region the model is deployed in, e.g. `"us-central1"`
+    /// * `model` - the publisher model name, e.g. `"gemini-1.5-flash"`
+    pub fn new(project_id: impl Into<String>, location: impl Into<String>, model: impl Into<String>) -> Result<Self> {
+        let base = ServiceBase::new_default(vec![VERTEX_AI_SERVICE_SCOPE])?;
+        Ok(Self {
+            base,
+            project_id: project_id.into(),
+            location: location.into(),
+            model: model.into(),
+        })
+    }
+
+    fn endpoint(&self, method: &str) -> String {
+        format!(
+            "https://{location}-aiplatform.googleapis.com/v1/projects/{project}/locations/{location}/publishers/google/models/{model}:{method}",
+            location = self.location,
+            project = self.project_id,
+            model = self.model,
+        )
+    }
+
+    /// Generate content for `contents` and return the full response.
+    pub async fn generate_content(&self, contents: Vec<Content>) -> Result<GenerateContentResponse> {
+        let request = GenerateContentRequest { contents };
+
+        let client = reqwest::Client::new();
+        let mut builder = client.post(self.endpoint("generateContent")).json(&request);
+        self.base.authenticate(&mut builder).await?;
+
+        let response = builder.send().await?;
+        let response = ensure_success(response).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Generate content for `contents`, streaming each candidate's text delta as it arrives
+    /// rather than waiting for the full response.
+    pub async fn stream_generate_content(
+        &self,
+        contents: Vec<Content>,
+    ) -> Result<impl Stream<Item = Result<GenerateContentResponse>>> {
+        let request = GenerateContentRequest { contents };
+
+        let client = reqwest::Client::new();
+        let mut builder = client
+            .post(self.endpoint("streamGenerateContent"))
+            .json(&request);
+        self.base.authenticate(&mut builder).await?;
+
+        let response = builder.send().await?;
+        let response = ensure_success(response).await?;
+
+        Ok(json_array_elements(response.bytes_stream()))
+    }
+}
+
+/// Adapt a byte stream carrying an incrementally-delivered JSON array (`[{...}, {...}, ...]`)
+/// into a stream of its parsed top-level elements, yielding each element as soon as its closing
+/// brace has arrived rather than waiting for the whole array to download.
+///
+/// Scanning works directly on the raw bytes rather than decoding each chunk to UTF-8 first: every
+/// byte this scanner matches on (`"`, `{`, `}`, `\`) is ASCII, and UTF-8 guarantees a multi-byte
+/// character's continuation bytes never equal an ASCII byte, so a character split across chunk
+/// boundaries can't be mistaken for JSON structure. The buffered bytes are only validated as UTF-8
+/// once a complete element has been sliced out, by `serde_json::from_slice`.
+fn json_array_elements(
+    mut bytes: impl Stream<Item = reqwest::Result<bytes::Bytes>> + Unpin,
+) -> impl Stream<Item = Result<GenerateContentResponse>> {
+    async_stream::try_stream! {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut element_start = None;
+        let mut i = 0;
+
+        while let Some(chunk) = bytes.next().await {
+            let chunk = chunk?;
+            buffer.extend_from_slice(&chunk);
+
+            while i < buffer.len() {
+                let c = buffer[i];
+
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if c == b'\\' {
+                        escaped = true;
+                    } else if c == b'"' {
+                        in_string = false;
+                    }
+                    i += 1;
+                    continue;
+                }
+
+                match c {
+                    b'"' => in_string = true,
+                    b'{' => {
+                        if depth == 0 {
+                            element_start = Some(i);
+                        }
+                        depth += 1;
+                    }
+                    b'}' => {
+                        depth = depth.checked_sub(1).ok_or_else(|| {
+                            GoogleError::Other("unbalanced `}` in streamed response".to_string())
+                        })?;
+                        if depth == 0 {
+                            if let Some(start) = element_start.take() {
+                                let element = &buffer[start..=i];
+                                let parsed: GenerateContentResponse = serde_json::from_slice(element)?;
+                                yield parsed;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+
+            // Drop everything up to (and including) the last fully-consumed element so the
+            // buffer doesn't grow unbounded across a long-running stream.
+            if depth == 0 {
+                buffer.clear();
+                i = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    fn chunk_stream(chunks: &[&str]) -> impl Stream<Item = reqwest::Result<bytes::Bytes>> {
+        stream::iter(
+            chunks
+                .iter()
+                .map(|c| Ok(bytes::Bytes::from(c.to_string())))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn sample_element(text: &str) -> String {
+        format!(
+            r#"{{"candidates":[{{"content":{{"role":"model","parts":[{{"text":"{text}"}}]}}}}]}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn yields_one_element_per_array_entry() {
+        let body = format!("[{},{}]", sample_element("hello"), sample_element("world"));
+        let stream = json_array_elements(chunk_stream(&[&body]));
+        let results: Vec<Result<GenerateContentResponse>> = stream.collect().await;
+
+        assert_eq!(results.len(), 2);
+        let first = results[0].as_ref().unwrap();
+        assert_eq!(first.candidates[0].content.parts[0].text.as_deref(), Some("hello"));
+        let second = results[1].as_ref().unwrap();
+        assert_eq!(second.candidates[0].content.parts[0].text.as_deref(), Some("world"));
+    }
+
+    #[tokio::test]
+    async fn yields_elements_as_chunks_split_mid_element_arrive() {
+        let element = sample_element("split across chunks");
+        let (left, right) = element.split_at(element.len() / 2);
+        let stream = json_array_elements(chunk_stream(&["[", left, right, "]"]));
+        let results: Vec<Result<GenerateContentResponse>> = stream.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].as_ref().unwrap().candidates[0].content.parts[0].text.as_deref(),
+            Some("split across chunks")
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_braces_inside_string_values() {
+        let body = sample_element("braces like { and } should not affect nesting");
+        let stream = json_array_elements(chunk_stream(&[&format!("[{body}]")]));
+        let results: Vec<Result<GenerateContentResponse>> = stream.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn unbalanced_closing_brace_is_an_error() {
+        let stream = json_array_elements(chunk_stream(&["}"]));
+        let results: Vec<Result<GenerateContentResponse>> = stream.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn multi_byte_character_split_across_chunks_is_not_corrupted() {
+        let element = sample_element("héllo");
+        let bytes = element.as_bytes();
+        // "é" is a two-byte UTF-8 sequence; split the chunk boundary in the middle of it, so
+        // neither half is valid UTF-8 on its own.
+        let split_at = element.find('é').unwrap() + 1;
+        let (left, right) = (&bytes[..split_at], &bytes[split_at..]);
+        assert!(std::str::from_utf8(left).is_err());
+
+        let chunks = vec![
+            Ok(bytes::Bytes::from_static(b"[")),
+            Ok(bytes::Bytes::copy_from_slice(left)),
+            Ok(bytes::Bytes::copy_from_slice(right)),
+            Ok(bytes::Bytes::from_static(b"]")),
+        ];
+        let stream = json_array_elements(stream::iter(chunks));
+        let results: Vec<Result<GenerateContentResponse>> = stream.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].as_ref().unwrap().candidates[0].content.parts[0].text.as_deref(),
+            Some("héllo")
+        );
+    }
+}